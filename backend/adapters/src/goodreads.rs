@@ -0,0 +1,235 @@
+/// Host that all Goodreads book pages are served from.
+const GOODREADS_HOST: &str = "goodreads.com";
+
+/// Strips an `http://` or `https://` scheme in any letter case, or returns `None` if the URL
+/// has no such scheme.
+fn strip_http_scheme(url: &str) -> Option<&str> {
+    let (scheme, rest) = url.split_once("://")?;
+    (scheme.eq_ignore_ascii_case("https") || scheme.eq_ignore_ascii_case("http")).then_some(rest)
+}
+
+/// Extracts the Goodreads book ID from a URL pasted by the user.
+///
+/// Accepts absolute and relative links to a book page, with or without a title slug
+/// (`/book/show/12345`, `/book/show/12345.Title`, `/book/show/12345-title`) or a locale prefix
+/// (`/en/book/show/12345`), and ignores ports, query strings and fragments. Returns `None` for
+/// anything that is not a book page, such as author or series pages, or links to other hosts.
+#[must_use]
+#[inline]
+pub fn parse_goodreads_id_from_url(url: &str) -> Option<String> {
+    let trimmed = url.trim();
+    let stripped = strip_http_scheme(trimmed);
+    let without_scheme = stripped.unwrap_or(trimmed);
+
+    // the first segment is a host if the link was absolute, or if it looks like a domain in a
+    // link pasted without its scheme
+    let (first_segment, remainder) = without_scheme
+        .split_once('/')
+        .unwrap_or((without_scheme, ""));
+    let path_and_query = if stripped.is_some() || first_segment.contains('.') {
+        let host = first_segment
+            .split_once(':')
+            .map_or(first_segment, |(host, _port)| host)
+            .to_ascii_lowercase();
+        if host != GOODREADS_HOST && !host.ends_with(".goodreads.com") {
+            return None;
+        }
+        remainder
+    } else {
+        without_scheme
+    };
+
+    let path = path_and_query.split(['?', '#']).next().unwrap_or_default();
+    let mut segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .peekable();
+    // localized pages carry a two-letter language code before the book path
+    let _locale: Option<&str> = segments.next_if(|segment| {
+        segment.len() == 2 && segment.bytes().all(|byte| byte.is_ascii_alphabetic())
+    });
+    if segments.next() != Some("book") || segments.next() != Some("show") {
+        return None;
+    }
+
+    let slug = segments.next()?;
+    let id_len = slug.chars().take_while(char::is_ascii_digit).count();
+    let (id, rest) = slug.split_at_checked(id_len)?;
+    let is_valid_suffix = rest.is_empty() || rest.starts_with(['.', '-', '_']);
+    (!id.is_empty() && is_valid_suffix).then(|| id.to_owned())
+}
+
+/// Hosts serving Goodreads cover images that understand Amazon's size tokens.
+const COVER_IMAGE_HOSTS: [&str; 4] = [
+    "media-amazon.com",
+    "images-amazon.com",
+    "ssl-images-amazon.com",
+    "gr-assets.com",
+];
+
+/// Rewrites a Goodreads cover URL to request the full-size image instead of a thumbnail.
+///
+/// Cover URLs scraped from Goodreads usually point at a thumbnail by carrying a size token
+/// between the image name and its extension, e.g. `2767052._SX98_.jpg` or
+/// `51x._SX318_BO1,204,203,200_.jpg`. Removing the token makes the CDN serve the original
+/// image. URLs from other hosts or without a recognized token are returned unchanged.
+#[must_use]
+#[inline]
+pub fn upgrade_cover_url(url: &str) -> String {
+    upgraded_cover_url(url).unwrap_or_else(|| url.to_owned())
+}
+
+#[allow(
+    clippy::single_call_fn,
+    reason = "keeps the `?`-based parsing separate from the fallback"
+)]
+/// Builds the full-size cover URL, or returns `None` if the URL has no recognized size token.
+fn upgraded_cover_url(url: &str) -> Option<String> {
+    let without_scheme = strip_http_scheme(url)?;
+    let (host, _) = without_scheme.split_once('/')?;
+    let host_lower = host.to_ascii_lowercase();
+    let is_cover_host = COVER_IMAGE_HOSTS.iter().any(|cover_host| {
+        host_lower == *cover_host || host_lower.ends_with(&format!(".{cover_host}"))
+    });
+    if !is_cover_host || url.contains(['?', '#']) {
+        return None;
+    }
+
+    let (directory, file_name) = url.rsplit_once('/')?;
+    let (stem, extension) = file_name.rsplit_once('.')?;
+    let (image_name, size_token) = stem.rsplit_once('.')?;
+    let is_size_token =
+        size_token.len() > 1 && size_token.starts_with('_') && size_token.ends_with('_');
+    (is_size_token && !image_name.is_empty())
+        .then(|| format!("{directory}/{image_name}.{extension}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_goodreads_id_from_url, upgrade_cover_url};
+
+    fn id(url: &str) -> Option<String> {
+        parse_goodreads_id_from_url(url)
+    }
+
+    #[test]
+    fn parses_book_page_slugs() {
+        let expected = Some("12345".to_owned());
+        assert_eq!(id("https://www.goodreads.com/book/show/12345"), expected);
+        assert_eq!(
+            id("https://www.goodreads.com/book/show/12345.Title"),
+            expected
+        );
+        assert_eq!(
+            id("https://www.goodreads.com/book/show/12345-title"),
+            expected
+        );
+        assert_eq!(
+            id("https://www.goodreads.com/book/show/12345_title"),
+            expected
+        );
+    }
+
+    #[test]
+    fn ignores_query_strings_and_fragments() {
+        let expected = Some("12345".to_owned());
+        assert_eq!(
+            id("https://www.goodreads.com/book/show/12345?from_search=true"),
+            expected
+        );
+        assert_eq!(
+            id("https://www.goodreads.com/book/show/12345-title#reviews"),
+            expected
+        );
+        assert_eq!(
+            id("  https://www.goodreads.com/book/show/12345?a=1#b  "),
+            expected
+        );
+    }
+
+    #[test]
+    fn parses_scheme_less_and_relative_links() {
+        let expected = Some("12345".to_owned());
+        assert_eq!(id("goodreads.com/book/show/12345"), expected);
+        assert_eq!(id("www.goodreads.com/book/show/12345-title"), expected);
+        assert_eq!(id("/book/show/12345.Title"), expected);
+        assert_eq!(id("book/show/12345"), expected);
+    }
+
+    #[test]
+    fn parses_localized_links_upper_case_schemes_and_ports() {
+        let expected = Some("12345".to_owned());
+        assert_eq!(
+            id("https://www.goodreads.com/en/book/show/12345-title"),
+            expected
+        );
+        assert_eq!(id("HTTPS://www.goodreads.com/book/show/12345"), expected);
+        assert_eq!(id("Http://WWW.Goodreads.com/book/show/12345"), expected);
+        assert_eq!(id("goodreads.com:443/book/show/12345"), expected);
+        assert_eq!(
+            id("https://www.goodreads.com:443/en/book/show/12345"),
+            expected
+        );
+    }
+
+    #[test]
+    fn rejects_other_pages_and_hosts() {
+        assert_eq!(
+            id("https://www.goodreads.com/author/show/1234.Author"),
+            None
+        );
+        assert_eq!(id("https://www.goodreads.com/series/4567-series"), None);
+        assert_eq!(id("https://www.goodreads.com/book/show/"), None);
+        assert_eq!(id("https://www.goodreads.com/book/show/title-12345"), None);
+        assert_eq!(id("https://www.amazon.com/book/show/12345"), None);
+        assert_eq!(
+            id("https://goodreads.com.example.org/book/show/12345"),
+            None
+        );
+        assert_eq!(id("ftp://www.goodreads.com/book/show/12345"), None);
+        assert_eq!(id("https://en/book/show/12345"), None);
+        assert_eq!(id("https://book/show/12345"), None);
+        assert_eq!(id("http://localhost:8080/book/show/12345"), None);
+        assert_eq!(id(""), None);
+    }
+
+    #[test]
+    fn strips_size_tokens_from_cover_urls() {
+        assert_eq!(
+            upgrade_cover_url(
+                "https://images-na.ssl-images-amazon.com/images/S/2767052._SX98_.jpg"
+            ),
+            "https://images-na.ssl-images-amazon.com/images/S/2767052.jpg"
+        );
+        assert_eq!(
+            upgrade_cover_url(
+                "https://m.media-amazon.com/images/I/51x._SX318_BO1,204,203,200_.jpg"
+            ),
+            "https://m.media-amazon.com/images/I/51x.jpg"
+        );
+        assert_eq!(
+            upgrade_cover_url(
+                "https://i.gr-assets.com/images/S/compressed.photo.goodreads.com/books/1388184640i/7235533._SY180_.jpg"
+            ),
+            "https://i.gr-assets.com/images/S/compressed.photo.goodreads.com/books/1388184640i/7235533.jpg"
+        );
+    }
+
+    #[test]
+    fn keeps_cover_urls_without_size_tokens() {
+        let full_size = "https://m.media-amazon.com/images/I/51x.jpg";
+        assert_eq!(upgrade_cover_url(full_size), full_size);
+        let compressed = "https://i.gr-assets.com/images/S/compressed.photo.goodreads.com/books/1388184640i/7235533.jpg";
+        assert_eq!(upgrade_cover_url(compressed), compressed);
+    }
+
+    #[test]
+    fn keeps_cover_urls_from_other_hosts_or_with_queries() {
+        let other_host = "https://example.com/images/2767052._SX98_.jpg";
+        assert_eq!(upgrade_cover_url(other_host), other_host);
+        let with_query = "https://m.media-amazon.com/images/I/51x._SX98_.jpg?width=98";
+        assert_eq!(upgrade_cover_url(with_query), with_query);
+        let relative = "/images/I/51x._SX98_.jpg";
+        assert_eq!(upgrade_cover_url(relative), relative);
+    }
+}
@@ -4,3 +4,6 @@
 
 // silence clippy by importing and not using
 use shared as _;
+
+/// Goodreads-specific parsing helpers.
+pub mod goodreads;
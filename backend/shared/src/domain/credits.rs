@@ -0,0 +1,196 @@
+use super::matching::normalize;
+
+/// Role a person had in creating a book.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContributorRole {
+    /// wrote the book.
+    Author,
+    /// edited the book or the collection it is part of.
+    Editor,
+    /// illustrated the book.
+    Illustrator,
+    /// translated the book from its original language.
+    Translator,
+    /// narrated the audio version of the book.
+    Narrator,
+    /// any other role, holding the unrecognized relator code.
+    Other(String),
+}
+
+impl ContributorRole {
+    /// Maps a MARC relator code, as used by EPUB `opf:role` attributes and `role` refinements,
+    /// to a role.
+    ///
+    /// Creators without a role are treated as authors, which is how EPUB readers interpret them
+    /// as well. Unknown codes are preserved in [`ContributorRole::Other`].
+    #[must_use]
+    #[inline]
+    pub fn from_marc_relator(code: &str) -> Self {
+        let normalized = code.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            "" | "aut" => Self::Author,
+            "edt" => Self::Editor,
+            "ill" => Self::Illustrator,
+            "trl" => Self::Translator,
+            "nrt" => Self::Narrator,
+            _ => Self::Other(normalized),
+        }
+    }
+
+    #[allow(
+        clippy::ref_patterns,
+        reason = "matching on `self` directly trips `pattern_type_mismatch` instead"
+    )]
+    /// Human-readable name of the role.
+    #[must_use]
+    #[inline]
+    pub fn label(&self) -> &str {
+        match *self {
+            Self::Author => "Author",
+            Self::Editor => "Editor",
+            Self::Illustrator => "Illustrator",
+            Self::Translator => "Translator",
+            Self::Narrator => "Narrator",
+            Self::Other(ref code) => code,
+        }
+    }
+}
+
+/// A person credited on a book, together with the role they had.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Contributor {
+    /// name as given in the book's metadata.
+    pub name: String,
+    /// role the person had in creating the book.
+    pub role: ContributorRole,
+}
+
+impl Contributor {
+    /// Creates a contributor with the given name and role.
+    #[must_use]
+    #[inline]
+    pub const fn new(name: String, role: ContributorRole) -> Self {
+        Self { name, role }
+    }
+}
+
+/// Returns the first author among the contributors, which is the one used for metadata searches.
+#[must_use]
+#[inline]
+pub fn primary_author(contributors: &[Contributor]) -> Option<&Contributor> {
+    contributors
+        .iter()
+        .find(|contributor| contributor.role == ContributorRole::Author)
+}
+
+/// Computes the key under which an author is stored, so that spelling variants of the same name
+/// resolve to the same author.
+///
+/// The name is [normalized](normalize) and consecutive initials are joined, which makes
+/// `"J.R.R. Tolkien"`, `"J. R. R.  Tolkien"` and `"JRR Tolkien"` share the key `"jrr tolkien"`.
+#[must_use]
+#[inline]
+pub fn author_name_key(name: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut previous_was_initial = false;
+    for word in normalize(name).split(' ') {
+        let is_initial = word.chars().count() == 1;
+        match words.last_mut() {
+            Some(last) if is_initial && previous_was_initial => last.push_str(word),
+            _ => words.push(word.to_owned()),
+        }
+        previous_was_initial = is_initial;
+    }
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Contributor, ContributorRole, author_name_key, primary_author};
+
+    #[test]
+    fn maps_known_relator_codes() {
+        assert_eq!(
+            ContributorRole::from_marc_relator("aut"),
+            ContributorRole::Author
+        );
+        assert_eq!(
+            ContributorRole::from_marc_relator("edt"),
+            ContributorRole::Editor
+        );
+        assert_eq!(
+            ContributorRole::from_marc_relator("ill"),
+            ContributorRole::Illustrator
+        );
+        assert_eq!(
+            ContributorRole::from_marc_relator("trl"),
+            ContributorRole::Translator
+        );
+        assert_eq!(
+            ContributorRole::from_marc_relator("nrt"),
+            ContributorRole::Narrator
+        );
+        assert_eq!(
+            ContributorRole::from_marc_relator(" TRL "),
+            ContributorRole::Translator
+        );
+    }
+
+    #[test]
+    fn treats_missing_relator_code_as_author() {
+        assert_eq!(
+            ContributorRole::from_marc_relator(""),
+            ContributorRole::Author
+        );
+        assert_eq!(
+            ContributorRole::from_marc_relator("  "),
+            ContributorRole::Author
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_relator_codes_lowercased() {
+        assert_eq!(
+            ContributorRole::from_marc_relator("CoV"),
+            ContributorRole::Other("cov".to_owned())
+        );
+    }
+
+    #[test]
+    fn primary_author_skips_other_roles() {
+        let contributors = [
+            Contributor::new("Jane Editor".to_owned(), ContributorRole::Editor),
+            Contributor::new("Ann Author".to_owned(), ContributorRole::Author),
+            Contributor::new("Bob Author".to_owned(), ContributorRole::Author),
+        ];
+        assert_eq!(
+            primary_author(&contributors).map(|author| author.name.as_str()),
+            Some("Ann Author")
+        );
+    }
+
+    #[test]
+    fn primary_author_is_none_without_authors() {
+        let contributors = [Contributor::new(
+            "Tom Translator".to_owned(),
+            ContributorRole::Translator,
+        )];
+        assert_eq!(primary_author(&contributors), None);
+        assert_eq!(primary_author(&[]), None);
+    }
+
+    #[test]
+    fn joins_initials_in_author_name_keys() {
+        assert_eq!(author_name_key("J.R.R. Tolkien"), "jrr tolkien");
+        assert_eq!(author_name_key("J. R. R.  Tolkien"), "jrr tolkien");
+        assert_eq!(author_name_key("JRR Tolkien"), "jrr tolkien");
+        assert_eq!(author_name_key("George R. R. Martin"), "george rr martin");
+    }
+
+    #[test]
+    fn keeps_single_trailing_initial_separate() {
+        assert_eq!(author_name_key("Malcolm X"), "malcolm x");
+    }
+}
@@ -0,0 +1,127 @@
+use super::credits::author_name_key;
+use super::isbn::to_isbn13;
+use super::matching::normalize;
+
+/// Computes a key that identifies a book independently of where or how it is stored.
+///
+/// Two records with the same key are considered the same book, which makes the key suitable for
+/// duplicate detection and for matching records during imports. The most reliable identifier
+/// available wins:
+///
+/// 1. the ISBN, [normalized](to_isbn13) to a bare ISBN-13, as `isbn:<digits>`,
+/// 2. the Goodreads ID, as `goodreads:<id>`,
+/// 3. the [normalized](normalize) title and the primary author's [name key](author_name_key), as
+///    `title:<title>|<author>`.
+///
+/// Blank identifiers, invalid ISBNs and Goodreads IDs that are not all digits are treated as
+/// missing. Returns `None` if no identifier is
+/// available and the title is blank, since such records cannot be told apart.
+#[must_use]
+#[inline]
+pub fn book_identity_key(
+    isbn: Option<&str>,
+    goodreads_id: Option<&str>,
+    title: &str,
+    primary_author: &str,
+) -> Option<String> {
+    if let Some(digits) = isbn.and_then(|raw| to_isbn13(raw).ok()) {
+        return Some(format!("isbn:{digits}"));
+    }
+
+    if let Some(id) = goodreads_id
+        .map(str::trim)
+        .filter(|id| !id.is_empty() && id.chars().all(|character| character.is_ascii_digit()))
+    {
+        return Some(format!("goodreads:{id}"));
+    }
+
+    let title_key = normalize(title);
+    (!title_key.is_empty())
+        .then(|| format!("title:{title_key}|{}", author_name_key(primary_author)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::book_identity_key;
+
+    #[test]
+    fn prefers_isbn_over_goodreads_id() {
+        assert_eq!(
+            book_identity_key(Some("978-0-306-40615-7"), Some("12345"), "Title", "Author"),
+            Some("isbn:9780306406157".to_owned())
+        );
+    }
+
+    #[test]
+    fn prefers_goodreads_id_over_title() {
+        assert_eq!(
+            book_identity_key(None, Some(" 12345 "), "Title", "Author"),
+            Some("goodreads:12345".to_owned())
+        );
+    }
+
+    #[test]
+    fn falls_through_blank_and_invalid_isbns() {
+        let expected = Some("goodreads:12345".to_owned());
+        assert_eq!(
+            book_identity_key(Some(""), Some("12345"), "Title", "Author"),
+            expected
+        );
+        assert_eq!(
+            book_identity_key(Some("  "), Some("12345"), "Title", "Author"),
+            expected
+        );
+        assert_eq!(
+            book_identity_key(Some("9780306406158"), Some("12345"), "Title", "Author"),
+            expected
+        );
+    }
+
+    #[test]
+    fn falls_back_to_title_and_author() {
+        assert_eq!(
+            book_identity_key(None, Some(""), "The Way of Kings!", "Brandon  Sanderson"),
+            Some("title:the way of kings|brandon sanderson".to_owned())
+        );
+        assert_eq!(
+            book_identity_key(None, None, "Beowulf", ""),
+            Some("title:beowulf|".to_owned())
+        );
+    }
+
+    #[test]
+    fn falls_through_goodreads_ids_that_are_not_numeric() {
+        let expected = Some("title:dune|frank herbert".to_owned());
+        assert_eq!(
+            book_identity_key(None, Some("abc"), "Dune", "Frank Herbert"),
+            expected
+        );
+        assert_eq!(
+            book_identity_key(None, Some("12a"), "Dune", "Frank Herbert"),
+            expected
+        );
+    }
+
+    #[test]
+    fn keys_authors_with_spaced_and_joined_initials_alike() {
+        let expected = Some("title:the hobbit|jrr tolkien".to_owned());
+        assert_eq!(
+            book_identity_key(None, None, "The Hobbit", "J.R.R. Tolkien"),
+            expected
+        );
+        assert_eq!(
+            book_identity_key(None, None, "The Hobbit", "J. R. R. Tolkien"),
+            expected
+        );
+        assert_eq!(
+            book_identity_key(None, None, "The Hobbit", "JRR Tolkien"),
+            expected
+        );
+    }
+
+    #[test]
+    fn has_no_key_without_identifiers_or_title() {
+        assert_eq!(book_identity_key(None, None, "", "Author"), None);
+        assert_eq!(book_identity_key(Some("bad"), Some(" "), " ?! ", ""), None);
+    }
+}
@@ -0,0 +1,223 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Prefix that turns the first nine digits of an ISBN-10 into an ISBN-13.
+const ISBN10_TO_13_PREFIX: [u32; 3] = [9, 7, 8];
+/// Book prefix of ISBN-13s that have no ISBN-10 equivalent.
+const ISBN13_PREFIX_979: [u32; 3] = [9, 7, 9];
+
+/// Reasons an ISBN is rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseIsbnError {
+    /// contains a character other than digits, hyphens, spaces or a final `X` check digit.
+    InvalidCharacter(char),
+    /// has neither 10 nor 13 digits, holding the number of digits found.
+    InvalidLength(usize),
+    /// the check digit does not match the other digits.
+    InvalidCheckDigit,
+    /// an ISBN-13 that does not start with the `978` or `979` book prefix.
+    InvalidPrefix,
+}
+
+impl Display for ParseIsbnError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::InvalidCharacter(character) => {
+                write!(f, "ISBN contains invalid character `{character}`")
+            }
+            Self::InvalidLength(length) => {
+                write!(f, "ISBN has {length} digits, expected 10 or 13")
+            }
+            Self::InvalidCheckDigit => f.write_str("ISBN check digit does not match"),
+            Self::InvalidPrefix => f.write_str("ISBN-13 does not start with 978 or 979"),
+        }
+    }
+}
+
+#[allow(
+    clippy::missing_trait_methods,
+    reason = "the error has no underlying source"
+)]
+impl Error for ParseIsbnError {}
+
+/// Validates an ISBN-10 or ISBN-13 and returns it as a bare ISBN-13.
+///
+/// Hyphens and whitespace are ignored, so `"978-0-06-302142-6"` and `"0 8044 2957 X"` are
+/// accepted. ISBN-10s are converted to their ISBN-13 equivalent, which makes the result suitable
+/// for lookups and comparisons regardless of how the ISBN was entered.
+///
+/// # Errors
+/// Returns [`ParseIsbnError`] if the input contains other characters, has the wrong number of
+/// digits, fails the check digit validation, or is an ISBN-13 without a `978` or `979` prefix.
+#[inline]
+pub fn to_isbn13(raw: &str) -> Result<String, ParseIsbnError> {
+    let digits = isbn_digits(raw)?;
+    match digits.len() {
+        10 => {
+            if checksum_isbn10(&digits) != 0 {
+                return Err(ParseIsbnError::InvalidCheckDigit);
+            }
+            let mut isbn13: Vec<u32> = ISBN10_TO_13_PREFIX
+                .into_iter()
+                .chain(digits.into_iter().take(9))
+                .collect();
+            isbn13.push(check_digit_isbn13(&isbn13));
+            Ok(to_isbn_string(&isbn13))
+        }
+        13 => {
+            if digits.contains(&10) || checksum_isbn13(&digits) != 0 {
+                return Err(ParseIsbnError::InvalidCheckDigit);
+            }
+            if !digits.starts_with(&ISBN10_TO_13_PREFIX) && !digits.starts_with(&ISBN13_PREFIX_979)
+            {
+                return Err(ParseIsbnError::InvalidPrefix);
+            }
+            Ok(to_isbn_string(&digits))
+        }
+        length => Err(ParseIsbnError::InvalidLength(length)),
+    }
+}
+
+#[allow(
+    clippy::single_call_fn,
+    reason = "keeps character validation separate from the length and checksum rules"
+)]
+/// Extracts the digits of an ISBN, with an `X` check digit represented as 10.
+fn isbn_digits(raw: &str) -> Result<Vec<u32>, ParseIsbnError> {
+    let mut digits = Vec::with_capacity(13);
+    let mut characters = raw
+        .chars()
+        .filter(|character| *character != '-' && !character.is_whitespace())
+        .peekable();
+    while let Some(character) = characters.next() {
+        let is_last = characters.peek().is_none();
+        match character.to_digit(10) {
+            Some(digit) => digits.push(digit),
+            None if is_last && digits.len() == 9 && matches!(character, 'X' | 'x') => {
+                digits.push(10);
+            }
+            None => return Err(ParseIsbnError::InvalidCharacter(character)),
+        }
+    }
+    Ok(digits)
+}
+
+#[allow(
+    clippy::arithmetic_side_effects,
+    clippy::integer_division_remainder_used,
+    clippy::single_call_fn,
+    reason = "weighted sums over at most 13 digits cannot overflow"
+)]
+/// Weighted ISBN-10 sum modulo 11, which is zero for a valid ISBN-10.
+fn checksum_isbn10(digits: &[u32]) -> u32 {
+    digits
+        .iter()
+        .zip((1..=10).rev())
+        .map(|(digit, weight)| digit * weight)
+        .sum::<u32>()
+        % 11
+}
+
+#[allow(
+    clippy::arithmetic_side_effects,
+    clippy::integer_division_remainder_used,
+    reason = "weighted sums over at most 13 digits cannot overflow"
+)]
+/// Weighted ISBN-13 sum modulo 10, which is zero for a valid ISBN-13.
+fn checksum_isbn13(digits: &[u32]) -> u32 {
+    digits
+        .iter()
+        .zip([1, 3].into_iter().cycle())
+        .map(|(digit, weight)| digit * weight)
+        .sum::<u32>()
+        % 10
+}
+
+#[allow(
+    clippy::arithmetic_side_effects,
+    clippy::integer_division_remainder_used,
+    clippy::single_call_fn,
+    reason = "the checksum is always below 10"
+)]
+/// Computes the check digit completing the first twelve digits of an ISBN-13.
+fn check_digit_isbn13(first_twelve: &[u32]) -> u32 {
+    (10 - checksum_isbn13(first_twelve)) % 10
+}
+
+/// Formats ISBN digits as a string of ASCII digits.
+fn to_isbn_string(digits: &[u32]) -> String {
+    digits
+        .iter()
+        .filter_map(|digit| char::from_digit(*digit, 10))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseIsbnError, to_isbn13};
+
+    #[test]
+    fn accepts_valid_isbn13s() {
+        assert_eq!(to_isbn13("9780306406157"), Ok("9780306406157".to_owned()));
+        assert_eq!(
+            to_isbn13("978-0-06-302142-6"),
+            Ok("9780063021426".to_owned())
+        );
+        assert_eq!(
+            to_isbn13(" 979 10 90636 07 1 "),
+            Ok("9791090636071".to_owned())
+        );
+    }
+
+    #[test]
+    fn converts_isbn10s_to_isbn13s() {
+        assert_eq!(to_isbn13("0-306-40615-2"), Ok("9780306406157".to_owned()));
+        assert_eq!(to_isbn13("0 8044 2957 X"), Ok("9780804429573".to_owned()));
+        assert_eq!(to_isbn13("080442957x"), Ok("9780804429573".to_owned()));
+    }
+
+    #[test]
+    fn rejects_wrong_check_digits() {
+        assert_eq!(
+            to_isbn13("9780306406158"),
+            Err(ParseIsbnError::InvalidCheckDigit)
+        );
+        assert_eq!(
+            to_isbn13("0306406153"),
+            Err(ParseIsbnError::InvalidCheckDigit)
+        );
+        assert_eq!(
+            to_isbn13("0804429579"),
+            Err(ParseIsbnError::InvalidCheckDigit)
+        );
+    }
+
+    #[test]
+    fn rejects_isbn13s_without_book_prefix() {
+        // valid EAN-13 check digit, but not a book
+        assert_eq!(
+            to_isbn13("4006381333931"),
+            Err(ParseIsbnError::InvalidPrefix)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_characters_and_lengths() {
+        assert_eq!(
+            to_isbn13("12a4567890"),
+            Err(ParseIsbnError::InvalidCharacter('a'))
+        );
+        assert_eq!(
+            to_isbn13("X123456789"),
+            Err(ParseIsbnError::InvalidCharacter('X'))
+        );
+        assert_eq!(
+            to_isbn13("978030640615X"),
+            Err(ParseIsbnError::InvalidCharacter('X'))
+        );
+        assert_eq!(to_isbn13("123"), Err(ParseIsbnError::InvalidLength(3)));
+        assert_eq!(to_isbn13(""), Err(ParseIsbnError::InvalidLength(0)));
+    }
+}
@@ -0,0 +1,221 @@
+/// Weight of the title similarity in [`MatchConfidence::score`].
+pub const TITLE_WEIGHT: f32 = 0.5;
+/// Weight of the author similarity in [`MatchConfidence::score`].
+pub const AUTHOR_WEIGHT: f32 = 0.3;
+/// Weight of the metadata completeness in [`MatchConfidence::score`].
+pub const COMPLETENESS_WEIGHT: f32 = 0.2;
+/// Score from which a metadata candidate may be applied without asking the user.
+pub const AUTO_ACCEPT_THRESHOLD: f32 = 0.85;
+
+/// Normalizes text for comparisons between metadata from different sources.
+///
+/// Letters are lowercased, every run of characters that are neither letters nor digits becomes a
+/// single space, and leading and trailing separators are dropped, so that `"J.R.R. Tolkien"` and
+/// `"j. r. r.  tolkien"` normalize to the same string.
+#[must_use]
+#[inline]
+pub fn normalize(text: &str) -> String {
+    text.split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Similarity of two strings after [`normalize`], from `0.0` (nothing in common) to `1.0` (equal).
+///
+/// Based on the Levenshtein distance relative to the length of the longer string.
+#[allow(
+    clippy::float_arithmetic,
+    reason = "similarity is a ratio by definition"
+)]
+#[must_use]
+#[inline]
+pub fn similarity(left: &str, right: &str) -> f32 {
+    let left_chars: Vec<char> = normalize(left).chars().collect();
+    let right_chars: Vec<char> = normalize(right).chars().collect();
+    let longest = left_chars.len().max(right_chars.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    let distance = edit_distance(&left_chars, &right_chars);
+    1.0 - to_f32(distance) / to_f32(longest)
+}
+
+#[allow(
+    clippy::single_call_fn,
+    reason = "the distance table is easier to follow on its own"
+)]
+/// Levenshtein distance between two character sequences.
+fn edit_distance(left: &[char], right: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    for (row, left_char) in left.iter().enumerate() {
+        let mut current = Vec::with_capacity(previous.len());
+        current.push(row.saturating_add(1));
+        for (right_char, pair) in right.iter().zip(previous.windows(2)) {
+            if let [diagonal, above] = *pair {
+                let left_cell = current.last().copied().unwrap_or_default();
+                let substitution = diagonal.saturating_add(usize::from(left_char != right_char));
+                let cost = substitution
+                    .min(above.saturating_add(1))
+                    .min(left_cell.saturating_add(1));
+                current.push(cost);
+            }
+        }
+        previous = current;
+    }
+    previous.last().copied().unwrap_or_default()
+}
+
+/// Converts a length to `f32`, saturating for lengths that no title or name reaches in practice.
+fn to_f32(length: usize) -> f32 {
+    f32::from(u16::try_from(length).unwrap_or(u16::MAX))
+}
+
+/// [`similarity`] of two values that both have to be present, or `0.0` if either is blank.
+fn evidence_similarity(book_value: &str, candidate_value: &str) -> f32 {
+    if normalize(book_value).is_empty() || normalize(candidate_value).is_empty() {
+        0.0
+    } else {
+        similarity(book_value, candidate_value)
+    }
+}
+
+/// How confident we are that a metadata candidate describes the book the user has.
+///
+/// Keeps the individual components next to the combined score so that the user can see why a
+/// candidate was or wasn't accepted automatically.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct MatchConfidence {
+    /// similarity between the book's title and the candidate's title.
+    pub title_similarity: f32,
+    /// similarity between the book's primary author and the candidate's primary author.
+    pub author_similarity: f32,
+    /// share of the candidate's metadata fields that are filled, between `0.0` and `1.0`.
+    pub completeness: f32,
+    /// weighted combination of the components, between `0.0` and `1.0`.
+    pub score: f32,
+}
+
+impl MatchConfidence {
+    /// Scores a candidate against the title and primary author known from the book itself.
+    ///
+    /// The score is `TITLE_WEIGHT * title + AUTHOR_WEIGHT * author + COMPLETENESS_WEIGHT *
+    /// completeness`. Title and author dominate because a complete record of the wrong book is
+    /// worse than an incomplete record of the right one. A title or author that is blank on either
+    /// side counts as no similarity at all, so that missing data is never taken as evidence.
+    #[allow(
+        clippy::float_arithmetic,
+        reason = "the score is a weighted sum of ratios"
+    )]
+    #[must_use]
+    #[inline]
+    pub fn new(
+        book_title: &str,
+        book_author: &str,
+        candidate_title: &str,
+        candidate_author: &str,
+        candidate_completeness: f32,
+    ) -> Self {
+        let title_similarity = evidence_similarity(book_title, candidate_title);
+        let author_similarity = evidence_similarity(book_author, candidate_author);
+        let completeness = candidate_completeness.clamp(0.0, 1.0);
+        let score = TITLE_WEIGHT.mul_add(
+            title_similarity,
+            AUTHOR_WEIGHT.mul_add(author_similarity, COMPLETENESS_WEIGHT * completeness),
+        );
+        Self {
+            title_similarity,
+            author_similarity,
+            completeness,
+            score,
+        }
+    }
+
+    /// Whether the candidate is good enough to be applied without asking the user.
+    #[must_use]
+    #[inline]
+    pub fn is_auto_acceptable(&self) -> bool {
+        self.score >= AUTO_ACCEPT_THRESHOLD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MatchConfidence, normalize, similarity};
+
+    #[allow(
+        clippy::float_arithmetic,
+        reason = "compares floats within a tolerance"
+    )]
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn normalizes_case_and_punctuation() {
+        assert_eq!(normalize("J.R.R. Tolkien"), "j r r tolkien");
+        assert_eq!(normalize("  The Way of Kings!  "), "the way of kings");
+        assert_eq!(normalize("?!"), "");
+    }
+
+    #[test]
+    fn scores_exact_matches_as_equal() {
+        assert_close(similarity("The Way of Kings", "the way of kings"), 1.0);
+        assert_close(similarity("Dune", "Dune!"), 1.0);
+    }
+
+    #[test]
+    fn scores_typos_by_edit_distance() {
+        assert_close(similarity("kitten", "sitting"), 1.0 - 3.0 / 7.0);
+        assert_close(similarity("Mistborn", "Mistborm"), 1.0 - 1.0 / 8.0);
+        assert_close(similarity("abc", "xyz"), 0.0);
+    }
+
+    #[test]
+    fn scores_empty_inputs() {
+        assert_close(similarity("", ""), 1.0);
+        assert_close(similarity("Dune", ""), 0.0);
+    }
+
+    #[test]
+    fn clamps_completeness() {
+        let over = MatchConfidence::new("Dune", "Frank Herbert", "Dune", "Frank Herbert", 1.5);
+        assert_close(over.completeness, 1.0);
+        assert_close(over.score, 1.0);
+        let under = MatchConfidence::new("Dune", "Frank Herbert", "Dune", "Frank Herbert", -1.0);
+        assert_close(under.completeness, 0.0);
+        assert_close(under.score, 0.8);
+    }
+
+    #[test]
+    fn accepts_only_scores_from_the_threshold() {
+        let complete = MatchConfidence::new("Dune", "Frank Herbert", "Dune", "Frank Herbert", 0.5);
+        assert_close(complete.score, 0.9);
+        assert!(complete.is_auto_acceptable());
+
+        let incomplete =
+            MatchConfidence::new("Dune", "Frank Herbert", "Dune", "Frank Herbert", 0.0);
+        assert!(!incomplete.is_auto_acceptable());
+
+        let other_author =
+            MatchConfidence::new("Dune", "Frank Herbert", "Dune", "Kevin J. Anderson", 1.0);
+        assert!(!other_author.is_auto_acceptable());
+    }
+
+    #[test]
+    fn treats_blank_authors_as_no_evidence() {
+        let both_blank = MatchConfidence::new("Dune", "", "Dune", "", 0.3);
+        assert_close(both_blank.author_similarity, 0.0);
+        assert!(!both_blank.is_auto_acceptable());
+
+        let candidate_blank = MatchConfidence::new("Dune", "Frank Herbert", "Dune", " ", 1.0);
+        assert_close(candidate_blank.author_similarity, 0.0);
+        assert!(!candidate_blank.is_auto_acceptable());
+    }
+}
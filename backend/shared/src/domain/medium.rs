@@ -0,0 +1,147 @@
+use super::matching::normalize;
+use super::stored::impl_string_forms;
+
+/// Physical or digital format of an edition.
+///
+/// Parsing with `FromStr` only accepts the stored string forms; free-form descriptions go through
+/// [`EditionFormat::from_description`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum EditionFormat {
+    /// electronic book, e.g. EPUB or Kindle.
+    Ebook,
+    /// hardcover print edition.
+    Hardcover,
+    /// paperback print edition, including mass market and trade paperbacks.
+    Paperback,
+    /// audio recording of the book.
+    Audiobook,
+    /// any format that is not recognized, or no format at all.
+    #[default]
+    Other,
+}
+
+impl EditionFormat {
+    /// Maps a free-form format description, as found in scraped or embedded metadata, to a format.
+    ///
+    /// Matching ignores case and punctuation and works on whole words, so `"Kindle Edition"`,
+    /// `"e-book"` and `"EPUB"` are all ebooks and `"Mass Market Paperback"` is a paperback, while
+    /// `"Notebook"` is not an ebook. Anything else is [`EditionFormat::Other`].
+    #[must_use]
+    #[inline]
+    pub fn from_description(description: &str) -> Self {
+        let normalized = normalize(description);
+        let words: Vec<&str> = normalized.split_whitespace().collect();
+        let contains_any = |phrases: &[&[&str]]| {
+            phrases
+                .iter()
+                .any(|phrase| words.windows(phrase.len()).any(|window| window == *phrase))
+        };
+
+        if contains_any(&[&["audio"], &["audiobook"], &["mp3"]]) {
+            Self::Audiobook
+        } else if contains_any(&[
+            &["ebook"],
+            &["e", "book"],
+            &["kindle"],
+            &["epub"],
+            &["nook"],
+            &["digital"],
+        ]) {
+            Self::Ebook
+        } else if contains_any(&[
+            &["hardcover"],
+            &["hard", "cover"],
+            &["hardback"],
+            &["library", "binding"],
+        ]) {
+            Self::Hardcover
+        } else if contains_any(&[
+            &["paperback"],
+            &["softcover"],
+            &["soft", "cover"],
+            &["mass", "market"],
+        ]) {
+            Self::Paperback
+        } else {
+            Self::Other
+        }
+    }
+}
+
+impl_string_forms!(EditionFormat, "edition format", {
+    Ebook => "ebook",
+    Hardcover => "hardcover",
+    Paperback => "paperback",
+    Audiobook => "audiobook",
+    Other => "other",
+});
+
+#[cfg(test)]
+mod tests {
+    use super::EditionFormat;
+    use crate::domain::stored::UnknownValue;
+
+    #[test]
+    fn maps_common_format_descriptions() {
+        assert_eq!(
+            EditionFormat::from_description("Kindle Edition"),
+            EditionFormat::Ebook
+        );
+        assert_eq!(
+            EditionFormat::from_description("e-book"),
+            EditionFormat::Ebook
+        );
+        assert_eq!(
+            EditionFormat::from_description("EPUB"),
+            EditionFormat::Ebook
+        );
+        assert_eq!(
+            EditionFormat::from_description("Audio CD"),
+            EditionFormat::Audiobook
+        );
+        assert_eq!(
+            EditionFormat::from_description("Audiobook"),
+            EditionFormat::Audiobook
+        );
+        assert_eq!(
+            EditionFormat::from_description("Mass Market Paperback"),
+            EditionFormat::Paperback
+        );
+        assert_eq!(
+            EditionFormat::from_description("Library Binding"),
+            EditionFormat::Hardcover
+        );
+        assert_eq!(
+            EditionFormat::from_description("Hardcover"),
+            EditionFormat::Hardcover
+        );
+    }
+
+    #[test]
+    fn matches_whole_words_only() {
+        assert_eq!(
+            EditionFormat::from_description("Notebook"),
+            EditionFormat::Other
+        );
+        assert_eq!(
+            EditionFormat::from_description("Audiologist's Guide"),
+            EditionFormat::Other
+        );
+        assert_eq!(EditionFormat::from_description(""), EditionFormat::Other);
+    }
+
+    #[test]
+    fn round_trips_through_strings() {
+        for format in EditionFormat::ALL {
+            assert_eq!(format.as_str().parse::<EditionFormat>(), Ok(format));
+        }
+        assert_eq!(
+            "Kindle Edition".parse::<EditionFormat>(),
+            Err(UnknownValue {
+                kind: "edition format",
+                value: "Kindle Edition".to_owned()
+            })
+        );
+    }
+}
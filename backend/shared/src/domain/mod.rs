@@ -0,0 +1,20 @@
+/// people credited on a book and the roles they had in creating it.
+pub mod credits;
+/// identity of books across records and sources.
+pub mod identity;
+/// validation and normalization of ISBNs.
+pub mod isbn;
+/// comparison of metadata from different sources and confidence in metadata candidates.
+pub mod matching;
+/// physical and digital formats of editions.
+pub mod medium;
+/// provenance of a book's metadata.
+pub mod provenance;
+/// series membership, positions of books within a series and consolidation of series names.
+pub mod series;
+/// sort strings for titles.
+pub mod sorting;
+/// string forms of values persisted as text.
+pub mod stored;
+/// reading status, ratings and reading pace.
+pub mod tracking;
@@ -0,0 +1,75 @@
+use super::stored::impl_string_forms;
+
+/// How a book's metadata was obtained.
+///
+/// Stored alongside the book so that users can judge the quality of its metadata, and so that
+/// books that never got online metadata can be found and enriched later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum MetadataSource {
+    /// origin not recorded, e.g. for books added before provenance was tracked.
+    #[default]
+    Unknown,
+    /// only the metadata embedded in the EPUB file.
+    EpubOnly,
+    /// Goodreads, matched by ISBN.
+    GoodreadsByIsbn,
+    /// Goodreads, matched by title and author search.
+    GoodreadsByTitle,
+    /// Goodreads, fetched by a known Goodreads ID.
+    GoodreadsById,
+    /// Goodreads, fetched from a URL supplied by the user.
+    GoodreadsByUrl,
+    /// Google Books.
+    GoogleBooks,
+    /// Open Library.
+    OpenLibrary,
+    /// entered or corrected by the user.
+    Manual,
+}
+
+impl_string_forms!(MetadataSource, "metadata source", {
+    Unknown => "unknown",
+    EpubOnly => "epub",
+    GoodreadsByIsbn => "goodreads-isbn",
+    GoodreadsByTitle => "goodreads-title",
+    GoodreadsById => "goodreads-id",
+    GoodreadsByUrl => "goodreads-url",
+    GoogleBooks => "google-books",
+    OpenLibrary => "open-library",
+    Manual => "manual",
+});
+
+#[cfg(test)]
+mod tests {
+    use super::MetadataSource;
+    use crate::domain::stored::UnknownValue;
+
+    #[test]
+    fn round_trips_through_strings() {
+        for source in MetadataSource::ALL {
+            assert_eq!(source.as_str().parse::<MetadataSource>(), Ok(source));
+            assert_eq!(source.to_string(), source.as_str());
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_strings() {
+        assert_eq!(
+            "Goodreads".parse::<MetadataSource>(),
+            Err(UnknownValue {
+                kind: "metadata source",
+                value: "Goodreads".to_owned()
+            })
+        );
+        assert!(matches!(
+            "".parse::<MetadataSource>(),
+            Err(UnknownValue { .. })
+        ));
+    }
+
+    #[test]
+    fn defaults_to_unknown() {
+        assert_eq!(MetadataSource::default(), MetadataSource::Unknown);
+    }
+}
@@ -0,0 +1,175 @@
+use super::matching::{normalize, similarity};
+
+/// Derives the numeric position of a book within a series from its position label.
+///
+/// Labels come in several shapes: plain numbers (`"3"`, `"0.5"`), ranges for omnibus editions
+/// (`"1-3"`), lists (`"1, 2"`) and non-numeric markers for companion volumes (`"A"`). Ranges and
+/// lists resolve to their first entry, which must consist of ASCII digits with at most one decimal
+/// point. Any other label, including signs, exponents and empty labels, yields `None`, in which
+/// case the book still belongs to the series but has no position to sort by.
+#[must_use]
+#[inline]
+pub fn parse_series_position(label: &str) -> Option<f32> {
+    // ranges may use a hyphen or an en dash
+    let first_entry = label.split([',', '-', '\u{2013}']).next()?.trim();
+    let is_decimal = first_entry
+        .chars()
+        .any(|character| character.is_ascii_digit())
+        && first_entry
+            .chars()
+            .all(|character| character.is_ascii_digit() || character == '.')
+        && first_entry.matches('.').count() <= 1;
+    if !is_decimal {
+        return None;
+    }
+    first_entry
+        .parse::<f32>()
+        .ok()
+        .filter(|position| position.is_finite())
+}
+
+/// Similarity from which two series names are suggested for consolidation.
+pub const SIMILAR_SERIES_THRESHOLD: f32 = 0.9;
+
+/// Computes the key under which series names are compared, so that `"Crime & Punishment"` and
+/// `"crime and punishment"` are recognized as the same series.
+///
+/// Ampersands are spelled out before the name is [normalized](normalize).
+#[must_use]
+#[inline]
+pub fn name_key(name: &str) -> String {
+    normalize(&name.replace('&', " and "))
+}
+
+/// Groups series names that likely refer to the same series, for suggesting merges.
+///
+/// Two names end up in the same group if they share a [`name_key`] or if their keys have a
+/// [`similarity`] of at least [`SIMILAR_SERIES_THRESHOLD`] and contain the same numbers, so that
+/// numbered series such as `"Mistborn Era 1"` and `"Mistborn Era 2"` or `"The Dark Tower I"` and
+/// `"The Dark Tower II"` stay apart. Numbers are recognized when written with digits or as Roman
+/// numerals up to XXXIX. Each name is compared against the first name of every existing group. The
+/// result holds the indices into `names` for every group with more than one member, in order of
+/// first appearance.
+#[must_use]
+#[inline]
+pub fn group_similar_names<S: AsRef<str>>(names: &[S]) -> Vec<Vec<usize>> {
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    for (index, name) in names.iter().enumerate() {
+        let key = name_key(name.as_ref());
+        let existing = groups.iter_mut().find(|group| {
+            group.0 == key
+                || (numeric_words(&group.0) == numeric_words(&key)
+                    && similarity(&group.0, &key) >= SIMILAR_SERIES_THRESHOLD)
+        });
+        match existing {
+            Some(group) => group.1.push(index),
+            None => groups.push((key, vec![index])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(_, members)| members)
+        .filter(|members| members.len() > 1)
+        .collect()
+}
+
+/// Words of a normalized name that are numbers, which have to match exactly.
+fn numeric_words(key: &str) -> Vec<&str> {
+    key.split(' ')
+        .filter(|word| {
+            word.chars().any(|character| character.is_ascii_digit()) || is_roman_numeral(word)
+        })
+        .collect()
+}
+
+#[allow(
+    clippy::single_call_fn,
+    reason = "keeps the numeral grammar out of the word filter"
+)]
+/// Whether a lowercase word is a Roman numeral from I to XXXIX, the range used for volumes.
+fn is_roman_numeral(word: &str) -> bool {
+    const UNITS: [&str; 10] = ["", "i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix"];
+    let units = word.trim_start_matches('x');
+    let tens = word
+        .chars()
+        .take_while(|character| *character == 'x')
+        .count();
+    !word.is_empty() && tens <= 3 && UNITS.contains(&units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group_similar_names, parse_series_position};
+
+    #[test]
+    fn parses_plain_positions() {
+        assert_eq!(parse_series_position("3"), Some(3.0));
+        assert_eq!(parse_series_position("0.5"), Some(0.5));
+        assert_eq!(parse_series_position(" 12 "), Some(12.0));
+    }
+
+    #[test]
+    fn resolves_ranges_and_lists_to_their_first_entry() {
+        assert_eq!(parse_series_position("1, 2"), Some(1.0));
+        assert_eq!(parse_series_position("1-3"), Some(1.0));
+        assert_eq!(parse_series_position("4\u{2013}6"), Some(4.0));
+        assert_eq!(parse_series_position("2.5 - 3"), Some(2.5));
+    }
+
+    #[test]
+    fn rejects_labels_without_a_plain_position() {
+        assert_eq!(parse_series_position("A"), None);
+        assert_eq!(parse_series_position(""), None);
+        assert_eq!(parse_series_position("."), None);
+        assert_eq!(parse_series_position("-1"), None);
+        assert_eq!(parse_series_position("1e3"), None);
+        assert_eq!(parse_series_position("+2"), None);
+        assert_eq!(parse_series_position("1.2.3"), None);
+        assert_eq!(parse_series_position("inf"), None);
+    }
+
+    #[test]
+    fn groups_names_differing_in_ampersands() {
+        let names = ["Crime & Punishment", "Dune", "crime and punishment"];
+        assert_eq!(group_similar_names(&names), vec![vec![0, 2]]);
+    }
+
+    #[test]
+    fn groups_names_with_small_typos() {
+        let names = [
+            "The Stormlight Archive",
+            "The Stormlight Archve",
+            "Mistborn",
+        ];
+        assert_eq!(group_similar_names(&names), vec![vec![0, 1]]);
+    }
+
+    #[test]
+    fn keeps_numbered_series_apart() {
+        let names = [
+            "Mistborn Era 1",
+            "Mistborn Era 2",
+            "Warhammer 40,000",
+            "Warhammer 30,000",
+        ];
+        assert_eq!(group_similar_names(&names), Vec::<Vec<usize>>::new());
+    }
+
+    #[test]
+    fn keeps_series_numbered_with_roman_numerals_apart() {
+        let names = [
+            "The Dark Tower I",
+            "The Dark Tower II",
+            "Dune Chronicles XII",
+            "Dune Chronicles XI",
+        ];
+        assert_eq!(group_similar_names(&names), Vec::<Vec<usize>>::new());
+    }
+
+    #[test]
+    fn still_groups_typos_in_words_made_of_numeral_letters() {
+        let names = ["Vivid Civil Wars", "Vivid Civil Wars!", "Vivd Civil Wars"];
+        assert_eq!(group_similar_names(&names), vec![vec![0, 1, 2]]);
+    }
+}
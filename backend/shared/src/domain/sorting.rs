@@ -0,0 +1,59 @@
+/// Leading English articles that are moved to the end of a title for sorting.
+const ARTICLES: [&str; 3] = ["The", "A", "An"];
+
+/// Computes the sort string of a book or series title by moving a leading article to the end.
+///
+/// `"The Stormlight Archive"` sorts as `"Stormlight Archive, The"` and `"A Memory of Light"` as
+/// `"Memory of Light, A"`. Titles without a leading article, or consisting of nothing but the
+/// article, are returned trimmed but otherwise unchanged.
+#[must_use]
+#[inline]
+pub fn title_sort(title: &str) -> String {
+    let trimmed = title.trim();
+    match trimmed.split_once(char::is_whitespace) {
+        Some((first_word, rest))
+            if ARTICLES
+                .iter()
+                .any(|article| first_word.eq_ignore_ascii_case(article)) =>
+        {
+            format!("{}, {first_word}", rest.trim_start())
+        }
+        _ => trimmed.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::title_sort;
+
+    #[test]
+    fn moves_leading_article_to_the_end() {
+        assert_eq!(
+            title_sort("The Stormlight Archive"),
+            "Stormlight Archive, The"
+        );
+        assert_eq!(title_sort("A Memory of Light"), "Memory of Light, A");
+        assert_eq!(
+            title_sort("an Echo of Things to Come"),
+            "Echo of Things to Come, an"
+        );
+    }
+
+    #[test]
+    fn ignores_surrounding_whitespace() {
+        assert_eq!(title_sort("  The   Way of Kings  "), "Way of Kings, The");
+    }
+
+    #[test]
+    fn keeps_article_only_titles() {
+        assert_eq!(title_sort("The"), "The");
+        assert_eq!(title_sort(" A "), "A");
+    }
+
+    #[test]
+    fn keeps_titles_without_article() {
+        assert_eq!(title_sort("Mistborn"), "Mistborn");
+        assert_eq!(title_sort("Theory of Everything"), "Theory of Everything");
+        assert_eq!(title_sort("Anathem"), "Anathem");
+    }
+}
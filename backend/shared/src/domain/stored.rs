@@ -0,0 +1,72 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Implements the stable string forms of a fieldless enum that is persisted as text.
+///
+/// Generates an `ALL` constant listing every variant in the given order, a `const fn as_str`, as
+/// well as `Display` and `FromStr` implementations that use these strings. Parsing an unknown
+/// string fails with an [`UnknownValue`] naming `$kind`, e.g. `"reading status"`.
+macro_rules! impl_string_forms {
+    ($type:ident, $kind:literal, { $($variant:ident => $string:literal),+ $(,)? }) => {
+        impl $type {
+            /// Every value, in declaration order.
+            pub const ALL: [Self; [$(stringify!($variant)),+].len()] = [$(Self::$variant),+];
+
+            /// Stable string representation, used when persisting the value.
+            #[must_use]
+            #[inline]
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $string,)+
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $type {
+            #[inline]
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::std::str::FromStr for $type {
+            type Err = $crate::domain::stored::UnknownValue;
+
+            #[inline]
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Self::ALL
+                    .into_iter()
+                    .find(|candidate| candidate.as_str() == value)
+                    .ok_or_else(|| $crate::domain::stored::UnknownValue {
+                        kind: $kind,
+                        value: value.to_owned(),
+                    })
+            }
+        }
+    };
+}
+
+pub(crate) use impl_string_forms;
+
+/// Error returned when parsing a string that is not the stored form of any value of a type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct UnknownValue {
+    /// human-readable name of the type that was parsed, e.g. `"reading status"`.
+    pub kind: &'static str,
+    /// string that was rejected.
+    pub value: String,
+}
+
+impl Display for UnknownValue {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} `{}`", self.kind, self.value)
+    }
+}
+
+#[allow(
+    clippy::missing_trait_methods,
+    reason = "the error has no underlying source"
+)]
+impl Error for UnknownValue {}
@@ -0,0 +1,188 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use super::stored::impl_string_forms;
+
+/// Where a user stands with reading a book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum ReadingStatus {
+    /// not started yet, which is the status of every newly added book.
+    #[default]
+    Unread,
+    /// currently being read.
+    Reading,
+    /// finished.
+    Read,
+}
+
+impl_string_forms!(ReadingStatus, "reading status", {
+    Unread => "unread",
+    Reading => "reading",
+    Read => "read",
+});
+
+/// A user's own rating of a book, from one to five stars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UserRating(u8);
+
+impl UserRating {
+    /// Lowest possible rating.
+    pub const MIN: u8 = 1;
+    /// Highest possible rating.
+    pub const MAX: u8 = 5;
+
+    /// Creates a rating, rejecting values outside of [`Self::MIN`] to [`Self::MAX`].
+    ///
+    /// # Errors
+    /// Returns [`InvalidRating`] with the rejected value if it is out of range.
+    #[inline]
+    pub const fn new(stars: u8) -> Result<Self, InvalidRating> {
+        if stars >= Self::MIN && stars <= Self::MAX {
+            Ok(Self(stars))
+        } else {
+            Err(InvalidRating(stars))
+        }
+    }
+
+    /// Number of stars.
+    #[must_use]
+    #[inline]
+    pub const fn stars(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for UserRating {
+    type Error = InvalidRating;
+
+    #[inline]
+    fn try_from(stars: u8) -> Result<Self, Self::Error> {
+        Self::new(stars)
+    }
+}
+
+/// Error returned when a rating is outside of the allowed range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InvalidRating(pub u8);
+
+impl Display for InvalidRating {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "rating {} is out of range, expected {} to {} stars",
+            self.0,
+            UserRating::MIN,
+            UserRating::MAX
+        )
+    }
+}
+
+#[allow(
+    clippy::missing_trait_methods,
+    reason = "the error has no underlying source"
+)]
+impl Error for InvalidRating {}
+
+/// Average number of books finished per day over a window of days.
+///
+/// Returns `None` for an empty window, as there is no pace to speak of.
+#[allow(clippy::float_arithmetic, reason = "the pace is a ratio")]
+#[must_use]
+#[inline]
+pub fn reading_velocity(books_read: u32, window_days: u32) -> Option<f64> {
+    (window_days > 0).then(|| f64::from(books_read) / f64::from(window_days))
+}
+
+/// Projects how many days it takes to finish the unread books at the given pace.
+///
+/// Returns `None` if nothing was read recently, since the pile would never be cleared at that
+/// pace. A pile without unread books is cleared in zero days.
+#[allow(clippy::float_arithmetic, reason = "the projection is a ratio")]
+#[must_use]
+#[inline]
+pub fn days_to_clear(unread_books: u32, books_per_day: f64) -> Option<f64> {
+    if unread_books == 0 {
+        return Some(0.0);
+    }
+    (books_per_day.is_finite() && books_per_day > 0.0)
+        .then(|| f64::from(unread_books) / books_per_day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidRating, ReadingStatus, UserRating, days_to_clear, reading_velocity};
+    use crate::domain::stored::UnknownValue;
+
+    #[test]
+    fn new_books_are_unread() {
+        assert_eq!(ReadingStatus::default(), ReadingStatus::Unread);
+    }
+
+    #[test]
+    fn reading_status_round_trips_through_strings() {
+        for status in ReadingStatus::ALL {
+            assert_eq!(status.as_str().parse::<ReadingStatus>(), Ok(status));
+            assert_eq!(status.to_string(), status.as_str());
+        }
+        assert_eq!(
+            "Finished".parse::<ReadingStatus>(),
+            Err(UnknownValue {
+                kind: "reading status",
+                value: "Finished".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn accepts_ratings_in_range() {
+        assert_eq!(UserRating::new(1).map(UserRating::stars), Ok(1));
+        assert_eq!(UserRating::new(5).map(UserRating::stars), Ok(5));
+    }
+
+    #[test]
+    fn rejects_ratings_out_of_range() {
+        assert_eq!(UserRating::new(0), Err(InvalidRating(0)));
+        assert_eq!(UserRating::new(6), Err(InvalidRating(6)));
+    }
+
+    #[test]
+    fn try_from_matches_new() {
+        for stars in 0..=u8::MAX {
+            assert_eq!(UserRating::try_from(stars), UserRating::new(stars));
+        }
+    }
+
+    #[test]
+    fn computes_reading_velocity() {
+        assert_eq!(reading_velocity(6, 30), Some(0.2f64));
+        assert_eq!(reading_velocity(0, 30), Some(0.0f64));
+    }
+
+    #[test]
+    fn has_no_reading_velocity_for_empty_window() {
+        assert_eq!(reading_velocity(3, 0), None);
+        assert_eq!(reading_velocity(0, 0), None);
+    }
+
+    #[test]
+    fn projects_days_to_clear() {
+        assert_eq!(days_to_clear(10, 0.5), Some(20.0f64));
+    }
+
+    #[test]
+    fn clears_empty_pile_immediately() {
+        assert_eq!(days_to_clear(0, 0.0), Some(0.0f64));
+        assert_eq!(days_to_clear(0, f64::NAN), Some(0.0f64));
+    }
+
+    #[test]
+    fn never_clears_pile_without_pace() {
+        assert_eq!(days_to_clear(10, 0.0), None);
+        assert_eq!(days_to_clear(10, -1.0), None);
+        assert_eq!(days_to_clear(10, f64::NAN), None);
+        assert_eq!(days_to_clear(10, f64::INFINITY), None);
+    }
+}